@@ -57,6 +57,166 @@ fn Select() -> impl IntoView {
     }
 }
 
+/* Loading Data with Resources,
+ * see: https://book.leptos.dev/async/10_resources.html */
+
+// Serialize/Deserialize are required here because create_resource's output
+// must implement leptos::Serializable, not just Clone.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct CatError(String);
+
+impl std::fmt::Display for CatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "error fetching cats: {}", self.0)
+    }
+}
+
+async fn fetch_cats(count: i32) -> Result<Vec<String>, CatError> {
+    // artificial delay so the <Suspense/> fallback is actually visible
+    gloo_timers::future::TimeoutFuture::new(500).await;
+
+    let url = format!("https://api.thecatapi.com/v1/images/search?limit={count}");
+    let json = gloo_net::http::Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| CatError(e.to_string()))?
+        .json::<Vec<serde_json::Value>>()
+        .await
+        .map_err(|e| CatError(e.to_string()))?;
+
+    Ok(json
+        .into_iter()
+        .filter_map(|cat| cat["url"].as_str().map(String::from))
+        .collect())
+}
+
+#[component]
+fn CatFetcher() -> impl IntoView {
+    let (count, set_count) = create_signal(1);
+    let cats = create_resource(count, |count| async move { fetch_cats(count).await });
+
+    let cat_list = move || {
+        cats.get().map(|result| match result {
+            Ok(urls) => view! {
+                <ul>
+                    {urls.into_iter().map(|url| view! { <li>{url}</li> }).collect_view()}
+                </ul>
+            }.into_view(),
+            Err(e) => view! { <p>{e.to_string()}</p> }.into_view(),
+        })
+    };
+
+    view! {
+        <div>
+            <label>
+                "Number of cats: "
+                <input
+                    type="number"
+                    prop:value=count
+                    on:input=move |ev| {
+                        if let Ok(value) = event_target_value(&ev).parse::<i32>() {
+                            set_count.set(value);
+                        }
+                    }
+                />
+            </label>
+            <h3>"<Suspense/>: flashes the fallback on every request"</h3>
+            <Suspense fallback=move || view! { <p>"Loading…"</p> }>
+                {cat_list}
+            </Suspense>
+            <h3>"<Transition/>: keeps old data on screen while loading"</h3>
+            <Transition fallback=move || view! { <p>"Loading…"</p> }>
+                {cat_list}
+            </Transition>
+        </div>
+    }
+}
+
+#[component]
+fn DynamicCounters() -> impl IntoView {
+    let mut next_id = 0;
+
+    let (counters, set_counters) = create_signal(Vec::<(usize, RwSignal<i32>)>::new());
+
+    let add_counter = move |_| {
+        let sig = create_rw_signal(0);
+        set_counters.update(move |counters| counters.push((next_id, sig)));
+        next_id += 1;
+    };
+
+    let remove_counter = move |_| {
+        set_counters.update(|counters| {
+            counters.pop();
+        });
+    };
+
+    let sum = move || counters.get().iter().map(|(_, count)| count.get()).sum::<i32>();
+
+    view! {
+        <div>
+            <button on:click=add_counter>"Add counter"</button>
+            <button on:click=remove_counter>"Remove"</button>
+            <p>"Sum: " {sum}</p>
+            <ul>
+                <For
+                    each=move || counters.get()
+                    key=|counter| counter.0
+                    children=move |(id, count)| {
+                        view! {
+                            <li>
+                                <button on:click=move |_| count.update(|n| *n += 1)>
+                                    {move || count.get()}
+                                </button>
+                                <button on:click=move |_| {
+                                    set_counters.update(|counters| counters.retain(|(counter_id, _)| *counter_id != id));
+                                }>
+                                    "Remove"
+                                </button>
+                            </li>
+                        }
+                    }
+                />
+            </ul>
+        </div>
+    }
+}
+
+#[component]
+fn DynamicSelect() -> impl IntoView {
+    let (options, set_options) = create_signal(vec![
+        (0, "Zero".to_string()),
+        (1, "One".to_string()),
+        (2, "Two".to_string()),
+    ]);
+    let (value, set_value) = create_signal(0i32);
+
+    let add_option = move |_| {
+        set_options.update(|options| {
+            let next_value = options.last().map(|(v, _)| v + 1).unwrap_or(0);
+            options.push((next_value, format!("Option {next_value}")));
+        });
+    };
+
+    view! {
+        <select
+            on:change=move |ev| {
+                let new_value = event_target_value(&ev);
+                set_value.set(new_value.parse().unwrap());
+            }
+            prop:value=move || value.get().to_string()
+        >
+            {move || options.get()
+                .iter()
+                .map(|(v, label)| view! { <option value=v.to_string()>{label.clone()}</option> })
+                .collect_view()
+            }
+        </select>
+        <button on:click=add_option>
+            "Add Option"
+        </button>
+    }
+}
+
 // Show progress toward a goal
 #[component]
 fn ProgressBar(
@@ -289,10 +449,16 @@ fn Watch() -> impl IntoView {
     set_num.set(2); // nothing happens
 }
 
+/* create_memo and batch,
+ * see: https://book.leptos.dev/reactivity/05_create_memo.html
+ * see: https://docs.rs/leptos/latest/leptos/fn.batch.html */
+
 #[component]
 fn App() -> impl IntoView {
     let (count, set_count) = create_signal(0);
-    let double_count = move || count.get() * 2;
+    // memoized, so it's only recomputed when `count` actually changes,
+    // rather than on every read
+    let double_count = create_memo(move |_| count.get() * 2);
 
     let values = vec![0, 1, 2];
 
@@ -319,6 +485,18 @@ fn App() -> impl IntoView {
     let (toggled, set_toggled) = create_signal(false);
     provide_context(set_toggled);
 
+    let (names, set_names) = create_signal(Vec::<String>::new());
+
+    // all three updates run inside a single batch, so effects and memos
+    // that depend on count/toggled/names only re-run once, not three times
+    let batch_update = move |_| {
+        batch(move || {
+            set_count.update(|n| *n += 1);
+            set_toggled.update(|value| *value = !*value);
+            set_names.update(|names| names.push("Batched Name".to_string()));
+        });
+    };
+
     view! {
         <button
             on:click=move |_| {
@@ -333,7 +511,7 @@ fn App() -> impl IntoView {
             "Click me: "
         </button>
         <br />
-        <ProgressBar progress=count />
+        <ProgressBar progress=double_count />
         <ProgressBar progress=double_count />
         <p>
             {move || count.get()}
@@ -350,12 +528,18 @@ fn App() -> impl IntoView {
         <ul>
             {counter_buttons}
         </ul>
+        <DynamicCounters />
+        <br />
+        <CatFetcher />
+        <br />
         <ControlledComponent />
         <UncontrolledComponent />
         <TextArea />
         <br />
         <Select />
         <br />
+        <DynamicSelect />
+        <br />
         <Show
             when=move || { count.get() > 5 }
             fallback=|| view! {<p>Small</p>}
@@ -365,6 +549,16 @@ fn App() -> impl IntoView {
         <NumericInput />
         <br />
         <p>"Toggled? " {toggled}</p>
+        <button on:click=batch_update>
+            "Batch Update (count, toggled, names)"
+        </button>
+        <ul>
+            {move || names.get()
+                .into_iter()
+                .map(|name| view! { <li>{name}</li> })
+                .collect_view()
+            }
+        </ul>
         <ButtonA setter=set_toggled/>
         <ButtonC on:click=move |_| set_toggled.update(|value| *value = !*value)/>
         <Layout />